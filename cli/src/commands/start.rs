@@ -60,6 +60,13 @@ pub struct Start {
     #[clap(long = "client")]
     pub client: Option<String>,
 
+    /// Specify the number of threads a prover uses for proving (if unset, the global thread pool is used).
+    #[clap(long = "proving-threads", requires = "prover")]
+    pub proving_threads: Option<usize>,
+    /// Specify the number of threads a prover uses for verifying (if unset, the global thread pool is used).
+    #[clap(long = "verifying-threads", requires = "prover")]
+    pub verifying_threads: Option<usize>,
+
     /// Specify the IP address and port of a peer to connect to.
     #[clap(default_value = "", long = "connect")]
     pub connect: String,
@@ -298,7 +305,9 @@ impl Start {
         match node_type {
             NodeType::Beacon => Node::new_beacon(self.node, rest_ip, account, &trusted_peers, genesis, cdn, self.dev).await,
             NodeType::Validator => Node::new_validator(self.node, rest_ip, account, &trusted_peers, genesis, cdn, self.dev).await,
-            NodeType::Prover => Node::new_prover(self.node, account, &trusted_peers, genesis, self.dev).await,
+            NodeType::Prover => Node::new_prover(
+                self.node, account, &trusted_peers, genesis, self.proving_threads, self.verifying_threads, self.dev,
+            ).await,
             NodeType::Client => Node::new_client(self.node, account, &trusted_peers, genesis, self.dev).await,
         }
     }
@@ -358,6 +367,24 @@ mod tests {
         ]);
     }
 
+    #[test]
+    fn test_parse_thread_pools() {
+        // Prover
+        let config = Start::try_parse_from(["snarkos", "--prover", "aleo1xx"].iter()).unwrap();
+        assert_eq!((config.proving_threads, config.verifying_threads), (None, None));
+        let config = Start::try_parse_from(
+            ["snarkos", "--prover", "aleo1xx", "--proving-threads", "6", "--verifying-threads", "2"].iter(),
+        )
+        .unwrap();
+        assert_eq!((config.proving_threads, config.verifying_threads), (Some(6), Some(2)));
+
+        // Other node types
+        assert!(Start::try_parse_from(["snarkos", "--beacon", "aleo1xx", "--proving-threads", "4"].iter()).is_err());
+        assert!(Start::try_parse_from(["snarkos", "--validator", "aleo1xx", "--proving-threads", "4"].iter()).is_err());
+        assert!(Start::try_parse_from(["snarkos", "--client", "aleo1xx", "--verifying-threads", "4"].iter()).is_err());
+        assert!(Start::try_parse_from(["snarkos", "--verifying-threads", "4"].iter()).is_err());
+    }
+
     #[test]
     fn test_parse_cdn() {
         // Beacon (Prod)
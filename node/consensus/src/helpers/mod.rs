@@ -14,6 +14,9 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
 
+mod summary;
+pub use summary::*;
+
 use super::*;
 
 /// Calculate the staking reward, given the starting supply and anchor time.
@@ -0,0 +1,96 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// The snarkOS library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkOS library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+use core::fmt;
+
+/// A summary of the verification-relevant sizes of a transaction.
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct TransactionSummary {
+    /// The number of input records (serial numbers).
+    num_inputs: usize,
+    /// The number of output records (commitments).
+    num_outputs: usize,
+    /// The value balance (fee), which is negative if the transaction outputs more than it spends.
+    value_balance: i64,
+}
+
+impl TransactionSummary {
+    /// Initializes a new transaction summary.
+    pub const fn new(num_inputs: usize, num_outputs: usize, value_balance: i64) -> Self {
+        Self { num_inputs, num_outputs, value_balance }
+    }
+
+    /// Returns the number of input records.
+    pub const fn num_inputs(&self) -> usize {
+        self.num_inputs
+    }
+
+    /// Returns the number of output records.
+    pub const fn num_outputs(&self) -> usize {
+        self.num_outputs
+    }
+
+    /// Returns the value balance.
+    pub const fn value_balance(&self) -> i64 {
+        self.value_balance
+    }
+}
+
+impl<N: Network> TryFrom<&Transaction<N>> for TransactionSummary {
+    type Error = anyhow::Error;
+
+    /// Returns the summary of the given transaction.
+    fn try_from(transaction: &Transaction<N>) -> Result<Self> {
+        Ok(Self::new(transaction.serial_numbers().count(), transaction.commitments().count(), transaction.fee()?))
+    }
+}
+
+impl fmt::Display for TransactionSummary {
+    /// Prints the summary as `inputs=<n> outputs=<n> value_balance=<signed balance>`.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "inputs={} outputs={} value_balance={}", self.num_inputs, self.num_outputs, self.value_balance)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::TestRng;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(TransactionSummary::new(2, 2, 0).to_string(), "inputs=2 outputs=2 value_balance=0");
+        assert_eq!(TransactionSummary::new(1, 2, 10).to_string(), "inputs=1 outputs=2 value_balance=10");
+        assert_eq!(TransactionSummary::new(2, 2, -500).to_string(), "inputs=2 outputs=2 value_balance=-500");
+        assert_eq!(
+            TransactionSummary::new(0, 1, i64::MIN).to_string(),
+            "inputs=0 outputs=1 value_balance=-9223372036854775808"
+        );
+    }
+
+    #[test]
+    fn test_try_from_transaction() {
+        let rng = &mut TestRng::default();
+
+        // A transfer spends one record, and outputs a record for the recipient and one for the change.
+        let transaction = crate::tests::test_helpers::sample_execution_transaction(rng);
+        let summary = TransactionSummary::try_from(&transaction).unwrap();
+        assert_eq!(summary, TransactionSummary::new(1, 2, transaction.fee().unwrap()));
+        assert_eq!(summary.to_string(), "inputs=1 outputs=2 value_balance=0");
+    }
+}
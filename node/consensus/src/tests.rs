@@ -14,7 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
 
-use snarkos_node_ledger::{Ledger, RecordsFilter};
+use snarkos_node_ledger::{Ledger, RecordMap, RecordsFilter};
 use snarkvm::{
     console::{
         account::{Address, PrivateKey, ViewKey},
@@ -110,6 +110,46 @@ pub(crate) mod test_helpers {
         CurrentConsensus::new(ledger, true).unwrap()
     }
 
+    /// Splits each unspent record of the given account in half, and advances to a block containing the splits.
+    pub(crate) fn split_unspent_records(
+        consensus: &CurrentConsensus,
+        private_key: &PrivateKey<CurrentNetwork>,
+        rng: &mut TestRng,
+    ) -> Block<CurrentNetwork> {
+        // Fetch the unspent records.
+        let records = consensus.ledger.find_unspent_records(&ViewKey::try_from(private_key).unwrap()).unwrap();
+        let num_records = records.len();
+
+        for (_, record) in records {
+            // Prepare the inputs.
+            let inputs =
+                [Value::Record(record.clone()), Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap()];
+            // Create a new transaction.
+            let transaction = Transaction::execute(
+                consensus.ledger.vm(),
+                private_key,
+                ProgramID::from_str("credits.aleo").unwrap(),
+                Identifier::from_str("split").unwrap(),
+                inputs.iter(),
+                None,
+                None,
+                rng,
+            )
+            .unwrap();
+            // Add the transaction to the memory pool.
+            consensus.add_unconfirmed_transaction(transaction).unwrap();
+        }
+        assert_eq!(consensus.memory_pool().num_unconfirmed_transactions(), num_records);
+
+        // Propose the next block.
+        let next_block = consensus.propose_next_block(private_key, rng).unwrap();
+        // Ensure the block is a valid next block.
+        consensus.check_next_block(&next_block).unwrap();
+        // Construct a next block.
+        consensus.advance_to_next_block(&next_block).unwrap();
+        next_block
+    }
+
     pub(crate) fn sample_program() -> Program<CurrentNetwork> {
         static INSTANCE: OnceCell<Program<CurrentNetwork>> = OnceCell::new();
         INSTANCE
@@ -343,59 +383,220 @@ fn test_ledger_execute() {
 
 #[test]
 #[traced_test]
-fn test_ledger_execute_many() {
+fn test_ledger_program_usage() {
     let rng = &mut TestRng::default();
 
     // Sample the genesis private key, view key, and address.
     let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
     let view_key = ViewKey::try_from(private_key).unwrap();
+    let address = Address::try_from(private_key).unwrap();
 
     // Sample the genesis consensus.
     let consensus = crate::tests::test_helpers::sample_genesis_consensus(rng);
+    let credits = ProgramID::<CurrentNetwork>::from_str("credits.aleo").unwrap();
+
+    // Adds a `credits.aleo` transaction for the given function and inputs to the memory pool.
+    let execute = |function: &str, inputs: &[Value<CurrentNetwork>], rng: &mut TestRng| {
+        let transaction = Transaction::execute(
+            consensus.ledger.vm(),
+            &private_key,
+            credits,
+            Identifier::from_str(function).unwrap(),
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        )
+        .unwrap();
+        consensus.add_unconfirmed_transaction(transaction).unwrap();
+    };
+    // Proposes and advances to the next block.
+    let advance = |rng: &mut TestRng| {
+        let next_block = consensus.propose_next_block(&private_key, rng).unwrap();
+        consensus.check_next_block(&next_block).unwrap();
+        consensus.advance_to_next_block(&next_block).unwrap();
+    };
 
-    for height in 1..6 {
-        // Fetch the unspent records.
-        let records: Vec<_> = consensus
-            .ledger
-            .find_records(&view_key, RecordsFilter::Unspent)
-            .unwrap()
-            .filter(|(_, record)| !record.gates().is_zero())
-            .collect();
-        assert_eq!(records.len(), 1 << (height - 1));
+    // Block 1: split the genesis record.
+    let records = consensus.ledger.find_unspent_records(&view_key).unwrap();
+    let (_, record) = records.get_index(0).unwrap();
+    execute(
+        "split",
+        &[Value::Record(record.clone()), Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap()],
+        rng,
+    );
+    advance(rng);
+
+    // Block 2: split the first record, and transfer from the second record.
+    let records = consensus.ledger.find_unspent_records(&view_key).unwrap();
+    assert_eq!(records.len(), 2);
+    let (_, first) = records.get_index(0).unwrap();
+    let (_, second) = records.get_index(1).unwrap();
+    execute(
+        "split",
+        &[Value::Record(first.clone()), Value::from_str(&format!("{}u64", ***first.gates() / 2)).unwrap()],
+        rng,
+    );
+    execute(
+        "transfer",
+        &[
+            Value::Record(second.clone()),
+            Value::from_str(&address.to_string()).unwrap(),
+            Value::from_str(&format!("{}u64", ***second.gates() / 2)).unwrap(),
+        ],
+        rng,
+    );
+    advance(rng);
+    assert_eq!(consensus.ledger.latest_height(), 2);
+
+    // Ensure each transaction is counted for `credits.aleo`, in the given range.
+    let usage = consensus.ledger.program_usage(1..3).unwrap();
+    assert_eq!(usage.len(), 1);
+    assert_eq!(usage.get(&credits), Some(&3));
+    assert_eq!(consensus.ledger.program_usage(1..2).unwrap().get(&credits), Some(&1));
+    assert_eq!(consensus.ledger.program_usage(2..3).unwrap().get(&credits), Some(&2));
+
+    // Ensure an empty range has no usage.
+    assert!(consensus.ledger.program_usage(3..3).unwrap().is_empty());
+    // Ensure a range past the latest block fails.
+    assert!(consensus.ledger.program_usage(1..4).is_err());
+}
 
-        for (_, record) in records {
-            // Prepare the inputs.
-            let inputs =
-                [Value::Record(record.clone()), Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap()];
-            // Create a new transaction.
-            let transaction = Transaction::execute(
-                consensus.ledger.vm(),
-                &private_key,
-                ProgramID::from_str("credits.aleo").unwrap(),
-                Identifier::from_str("split").unwrap(),
-                inputs.iter(),
-                None,
-                None,
-                rng,
-            )
-            .unwrap();
-            // Add the transaction to the memory pool.
-            consensus.add_unconfirmed_transaction(transaction).unwrap();
-        }
-        assert_eq!(consensus.memory_pool().num_unconfirmed_transactions(), 1 << (height - 1));
+#[test]
+#[traced_test]
+fn test_ledger_execute_many() {
+    let rng = &mut TestRng::default();
 
-        // Propose the next block.
-        let next_block = consensus.propose_next_block(&private_key, rng).unwrap();
+    // Sample the genesis private key, view key, and address.
+    let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
+    let view_key = ViewKey::try_from(private_key).unwrap();
 
-        // Ensure the block is a valid next block.
-        consensus.check_next_block(&next_block).unwrap();
-        // Construct a next block.
-        consensus.advance_to_next_block(&next_block).unwrap();
+    // Sample the genesis consensus.
+    let consensus = crate::tests::test_helpers::sample_genesis_consensus(rng);
+
+    for height in 1..6 {
+        // Ensure the number of unspent records doubles with each block.
+        assert_eq!(consensus.ledger.find_unspent_records(&view_key).unwrap().len(), 1 << (height - 1));
+
+        // Split the unspent records, and advance to the next block.
+        let next_block = crate::tests::test_helpers::split_unspent_records(&consensus, &private_key, rng);
+        assert_eq!(next_block.transactions().len(), 1 << (height - 1));
         assert_eq!(consensus.ledger.latest_height(), height);
         assert_eq!(consensus.ledger.latest_hash(), next_block.hash());
     }
 }
 
+#[test]
+#[traced_test]
+fn test_ledger_generate_transactions() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key and view key, and a second account.
+    let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
+    let view_key = ViewKey::try_from(private_key).unwrap();
+    let other_private_key = PrivateKey::<CurrentNetwork>::new(rng).unwrap();
+    let other_view_key = ViewKey::try_from(other_private_key).unwrap();
+    let other_address = Address::try_from(other_private_key).unwrap();
+
+    // Sample the genesis consensus.
+    let consensus = crate::tests::test_helpers::sample_genesis_consensus(rng);
+
+    // Split the genesis record until there are enough records to spend.
+    for _ in 1..5 {
+        crate::tests::test_helpers::split_unspent_records(&consensus, &private_key, rng);
+    }
+    assert_eq!(consensus.ledger.find_unspent_records(&view_key).unwrap().len(), 16);
+
+    // Fund the second account, by transferring half of four records to it.
+    for (_, record) in consensus.ledger.find_unspent_records(&view_key).unwrap().into_iter().take(4) {
+        // Prepare the inputs.
+        let inputs = [
+            Value::Record(record.clone()),
+            Value::from_str(&other_address.to_string()).unwrap(),
+            Value::from_str(&format!("{}u64", ***record.gates() / 2)).unwrap(),
+        ];
+        // Create a new transaction.
+        let transaction = Transaction::execute(
+            consensus.ledger.vm(),
+            &private_key,
+            ProgramID::from_str("credits.aleo").unwrap(),
+            Identifier::from_str("transfer").unwrap(),
+            inputs.iter(),
+            None,
+            None,
+            rng,
+        )
+        .unwrap();
+        // Add the transaction to the memory pool.
+        consensus.add_unconfirmed_transaction(transaction).unwrap();
+    }
+    let next_block = consensus.propose_next_block(&private_key, rng).unwrap();
+    consensus.check_next_block(&next_block).unwrap();
+    consensus.advance_to_next_block(&next_block).unwrap();
+
+    // Retrieve the unspent records of both accounts.
+    let records = consensus.ledger.find_unspent_records(&view_key).unwrap();
+    let other_records = consensus.ledger.find_unspent_records(&other_view_key).unwrap();
+    assert_eq!(records.len(), 16);
+    assert_eq!(other_records.len(), 4);
+
+    // Generate the transactions.
+    let transactions = consensus.ledger.generate_transactions(&[private_key, other_private_key], 10, rng).unwrap();
+    assert_eq!(transactions.len(), 10);
+
+    // Ensure the transactions are valid, and do not conflict with each other.
+    for transaction in &transactions {
+        consensus.check_transaction_basic(transaction).unwrap();
+        consensus.add_unconfirmed_transaction(transaction.clone()).unwrap();
+    }
+    let serial_numbers = transactions.iter().flat_map(|transaction| transaction.serial_numbers()).collect::<Vec<_>>();
+    assert_eq!(serial_numbers.len(), serial_numbers.iter().collect::<std::collections::HashSet<_>>().len());
+
+    // Ensure all of the transactions are included in the next block.
+    let next_block = consensus.propose_next_block(&private_key, rng).unwrap();
+    consensus.check_next_block(&next_block).unwrap();
+    assert_eq!(next_block.transactions().len(), 10);
+    consensus.advance_to_next_block(&next_block).unwrap();
+
+    // Ensure both accounts are senders: the records alternate between the accounts until the second runs out.
+    let num_spent = |view_key: &ViewKey<CurrentNetwork>, records: &RecordMap<CurrentNetwork>| {
+        let unspent = consensus.ledger.find_unspent_records(view_key).unwrap();
+        records.keys().filter(|commitment| !unspent.contains_key(*commitment)).count()
+    };
+    assert_eq!(num_spent(&view_key, &records), 6);
+    assert_eq!(num_spent(&other_view_key, &other_records), 4);
+
+    // Ensure requesting more transactions than there are records to spend fails.
+    let num_records = consensus.ledger.find_unspent_records(&view_key).unwrap().len();
+    assert!(consensus.ledger.generate_transactions(&[private_key], num_records + 1, rng).is_err());
+}
+
+#[test]
+#[traced_test]
+fn test_ledger_verify_checkpoint() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
+    // Sample the genesis consensus.
+    let consensus = crate::tests::test_helpers::sample_genesis_consensus(rng);
+    let genesis = consensus.ledger.get_block(0).unwrap();
+
+    // Advance to the next block.
+    let next_block = crate::tests::test_helpers::split_unspent_records(&consensus, &private_key, rng);
+    assert_eq!(consensus.ledger.latest_height(), 1);
+
+    // Ensure a matching checkpoint at height 1 passes.
+    assert!(consensus.ledger.verify_checkpoint(1, &next_block.hash()).is_ok());
+    // Ensure a mismatching checkpoint at height 1 fails.
+    assert!(consensus.ledger.verify_checkpoint(1, &genesis.hash()).is_err());
+    assert!(consensus.ledger.verify_checkpoint(1, &Default::default()).is_err());
+    // Ensure the genesis checkpoint still passes.
+    assert!(consensus.ledger.verify_checkpoint(0, &genesis.hash()).is_ok());
+    // Ensure a checkpoint for a missing block fails.
+    assert!(consensus.ledger.verify_checkpoint(2, &next_block.hash()).is_err());
+}
+
 #[test]
 #[traced_test]
 fn test_proof_target() {
@@ -23,6 +23,9 @@ mod contains;
 mod find;
 mod get;
 mod iterators;
+mod select;
+
+pub use select::select_coins;
 
 #[cfg(test)]
 mod tests;
@@ -46,9 +49,9 @@ use snarkvm::{
 use aleo_std::prelude::{finish, lap, timer};
 use anyhow::Result;
 use core::ops::Range;
-use indexmap::IndexMap;
+use indexmap::{IndexMap, IndexSet};
 use parking_lot::RwLock;
-use rand::{prelude::IteratorRandom, rngs::OsRng};
+use rand::{prelude::IteratorRandom, rngs::OsRng, CryptoRng, Rng};
 use std::{borrow::Cow, sync::Arc};
 
 #[cfg(feature = "parallel")]
@@ -266,6 +269,32 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         Ok(())
     }
 
+    /// Checks that the block hash at the given height matches the given trusted checkpoint hash.
+    pub fn verify_checkpoint(&self, height: u32, expected_block_hash: &N::BlockHash) -> Result<()> {
+        // Retrieve the block hash at the given height.
+        let block_hash = self.get_hash(height)?;
+        // Ensure the block hash matches the checkpoint.
+        if block_hash != *expected_block_hash {
+            bail!("Block {height} has hash '{block_hash}', which does not match the checkpoint '{expected_block_hash}'")
+        }
+        Ok(())
+    }
+
+    /// Returns the number of transactions that call each program, in the given range of block heights.
+    pub fn program_usage(&self, heights: Range<u32>) -> Result<IndexMap<ProgramID<N>, u64>> {
+        let mut usage = IndexMap::new();
+        for height in heights {
+            for transaction in self.get_transactions(height)?.values() {
+                // Count each program at most once per transaction.
+                let program_ids = transaction.transitions().map(|transition| *transition.program_id());
+                for program_id in program_ids.collect::<IndexSet<_>>() {
+                    *usage.entry(program_id).or_insert(0) += 1;
+                }
+            }
+        }
+        Ok(usage)
+    }
+
     /// Returns the unspent records.
     pub fn find_unspent_records(&self, view_key: &ViewKey<N>) -> Result<RecordMap<N>> {
         Ok(self
@@ -280,15 +309,70 @@ impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
         let records = self.find_unspent_records(&ViewKey::try_from(private_key)?)?;
         ensure!(!records.len().is_zero(), "The Aleo account has no records to spend.");
 
-        // Initialize an RNG.
-        let rng = &mut rand::thread_rng();
+        // Create the transfer from the first unspent record.
+        self.create_transfer_from_record(
+            private_key,
+            records.values().next().unwrap().clone(),
+            to,
+            amount,
+            &mut rand::thread_rng(),
+        )
+    }
+
+    /// Creates `count` transfer transactions among the given accounts, for load testing.
+    /// Each transaction spends a distinct unspent record, so the transactions do not conflict with each other.
+    #[cfg(feature = "test-helpers")]
+    pub fn generate_transactions<R: Rng + CryptoRng>(
+        &self,
+        private_keys: &[PrivateKey<N>],
+        count: usize,
+        rng: &mut R,
+    ) -> Result<Vec<Transaction<N>>> {
+        ensure!(!private_keys.is_empty(), "No accounts were provided to generate transactions.");
+
+        // Fetch the unspent records of each account.
+        let records = private_keys
+            .iter()
+            .map(|private_key| self.find_unspent_records(&ViewKey::try_from(private_key)?))
+            .collect::<Result<Vec<_>>>()?;
+        let num_records = records.iter().map(IndexMap::len).sum::<usize>();
+        ensure!(
+            num_records >= count,
+            "The accounts have {num_records} records to spend, but {count} transactions were requested."
+        );
+
+        // Select the first `count` records to spend, alternating between the accounts.
+        let max_num_records = records.iter().map(IndexMap::len).max().unwrap_or(0);
+        let selected = (0..max_num_records)
+            .flat_map(|index| {
+                records
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(sender, records)| records.get_index(index).map(|(_, record)| (sender, record)))
+            })
+            .take(count);
+
+        // Create a transfer from each sender to the next account.
+        selected
+            .map(|(sender, record)| {
+                let to = Address::try_from(&private_keys[(sender + 1) % private_keys.len()])?;
+                self.create_transfer_from_record(&private_keys[sender], record.clone(), to, 1, rng)
+            })
+            .collect()
+    }
 
+    /// Creates a transfer transaction that spends the given record.
+    fn create_transfer_from_record<R: Rng + CryptoRng>(
+        &self,
+        private_key: &PrivateKey<N>,
+        record: Record<N, Plaintext<N>>,
+        to: Address<N>,
+        amount: u64,
+        rng: &mut R,
+    ) -> Result<Transaction<N>> {
         // Prepare the inputs.
-        let inputs = [
-            Value::Record(records.values().next().unwrap().clone()),
-            Value::from_str(&format!("{to}"))?,
-            Value::from_str(&format!("{amount}u64"))?,
-        ];
+        let inputs =
+            [Value::Record(record), Value::from_str(&format!("{to}"))?, Value::from_str(&format!("{amount}u64"))?];
 
         // Create a new transaction.
         Transaction::execute(
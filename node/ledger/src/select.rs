@@ -0,0 +1,231 @@
+// Copyright (C) 2019-2022 Aleo Systems Inc.
+// This file is part of the snarkOS library.
+
+// The snarkOS library is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+
+// The snarkOS library is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE. See the
+// GNU General Public License for more details.
+
+// You should have received a copy of the GNU General Public License
+// along with the snarkOS library. If not, see <https://www.gnu.org/licenses/>.
+
+use super::*;
+
+/// The maximum number of branches to explore when searching for the best record set.
+const MAX_SELECTION_TRIES: usize = 100_000;
+
+impl<N: Network, C: ConsensusStorage<N>> Ledger<N, C> {
+    /// Returns the unspent records to spend for the given `target`, as selected by `select_coins`.
+    pub fn select_unspent_records(&self, view_key: &ViewKey<N>, target: u64) -> Result<RecordMap<N>> {
+        select_coins(&self.find_unspent_records(view_key)?, target)
+    }
+}
+
+/// Returns a subset of the given records whose gates sum to at least the given `target`.
+///
+/// The selection minimizes the number of records, breaking ties by the overshoot (change)
+/// above the target. It starts from a largest-first selection, which has the fewest records,
+/// and then runs a bounded branch-and-bound search for a candidate with a smaller overshoot.
+pub fn select_coins<N: Network>(records: &RecordMap<N>, target: u64) -> Result<RecordMap<N>> {
+    // Retrieve the gates of each record.
+    let amounts = records.values().map(|record| ***record.gates()).collect::<Vec<u64>>();
+
+    // Select the records.
+    match select_amounts(&amounts, target) {
+        Some(indices) => Ok(indices
+            .into_iter()
+            .map(|index| {
+                let (commitment, record) = records.get_index(index).expect("Selected an out-of-range record index");
+                (*commitment, record.clone())
+            })
+            .collect()),
+        None => {
+            let balance = amounts.iter().map(|amount| *amount as u128).sum::<u128>();
+            bail!("Insufficient funds to select records for {target} gates (balance is {balance} gates)")
+        }
+    }
+}
+
+/// Returns the indices of the selected amounts, or `None` if the amounts do not cover the `target`.
+fn select_amounts(amounts: &[u64], target: u64) -> Option<Vec<usize>> {
+    // Sort the indices by amount, in descending order.
+    let mut order = (0..amounts.len()).collect::<Vec<_>>();
+    order.sort_by(|a, b| amounts[*b].cmp(&amounts[*a]));
+
+    // Compute the largest-first selection, which is the initial candidate.
+    let mut best = Vec::new();
+    let mut best_sum = 0u128;
+    for index in &order {
+        if best_sum >= target as u128 {
+            break;
+        }
+        best.push(*index);
+        best_sum += amounts[*index] as u128;
+    }
+    // Ensure the amounts cover the target.
+    if best_sum < target as u128 {
+        return None;
+    }
+
+    // Compute the suffix sums, to prune branches that can no longer reach the target.
+    let mut remaining = vec![0u128; order.len() + 1];
+    for i in (0..order.len()).rev() {
+        remaining[i] = remaining[i + 1] + amounts[order[i]] as u128;
+    }
+
+    // Search for a selection with fewer records, or an equal number of records with a smaller overshoot.
+    let mut search =
+        Search { amounts, order: &order, remaining: &remaining, target: target as u128, best, best_sum, tries: 0 };
+    search.explore(0, 0, &mut Vec::new());
+
+    Some(search.best)
+}
+
+/// The state of a bounded branch-and-bound search over the amounts.
+struct Search<'a> {
+    /// The amounts to select from.
+    amounts: &'a [u64],
+    /// The indices of the amounts, in descending order of amount.
+    order: &'a [usize],
+    /// The sum of the amounts from each position in `order` onwards.
+    remaining: &'a [u128],
+    /// The target to cover.
+    target: u128,
+    /// The best selection found so far.
+    best: Vec<usize>,
+    /// The sum of the best selection found so far.
+    best_sum: u128,
+    /// The number of branches explored so far.
+    tries: usize,
+}
+
+impl Search<'_> {
+    /// Explores the inclusion and exclusion of the amount at `depth`, updating the best selection on improvement.
+    fn explore(&mut self, depth: usize, sum: u128, selection: &mut Vec<usize>) {
+        self.tries += 1;
+        if self.tries > MAX_SELECTION_TRIES {
+            return;
+        }
+
+        // If the target is reached, record the selection if it improves on the best selection.
+        if sum >= self.target {
+            if (selection.len(), sum) < (self.best.len(), self.best_sum) {
+                self.best = selection.clone();
+                self.best_sum = sum;
+            }
+            return;
+        }
+        // Prune if the target is unreachable, or if this branch can no longer improve on the best selection.
+        if depth == self.order.len() || sum + self.remaining[depth] < self.target || selection.len() >= self.best.len()
+        {
+            return;
+        }
+
+        // Include the amount at this depth.
+        let index = self.order[depth];
+        selection.push(index);
+        self.explore(depth + 1, sum + self.amounts[index] as u128, selection);
+        selection.pop();
+
+        // Exclude the amount at this depth.
+        self.explore(depth + 1, sum, selection);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::{console::network::Testnet3, prelude::TestRng};
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns the sum of the selected amounts.
+    fn sum_of(amounts: &[u64], indices: &[usize]) -> u64 {
+        indices.iter().map(|index| amounts[*index]).sum()
+    }
+
+    /// Returns a record map with one record for each of the given amounts, keyed by a random commitment.
+    fn sample_records(amounts: &[u64], rng: &mut TestRng) -> RecordMap<CurrentNetwork> {
+        let address = Address::try_from(PrivateKey::<CurrentNetwork>::new(rng).unwrap()).unwrap();
+        amounts
+            .iter()
+            .map(|gates| {
+                let nonce = Group::<CurrentNetwork>::rand(rng);
+                let record =
+                    format!("{{ owner: {address}.private, gates: {gates}u64.private, _nonce: {nonce}.public }}");
+                (Field::rand(rng), Record::from_str(&record).unwrap())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_select_amounts_exact_match() {
+        let amounts = [10, 7, 5, 3];
+
+        // The largest-first selection (10 + 7) overshoots, whereas (10 + 5) is exact.
+        let indices = select_amounts(&amounts, 15).unwrap();
+        assert_eq!(sum_of(&amounts, &indices), 15);
+        assert_eq!(indices.len(), 2);
+
+        // A single record that exactly matches the target is selected on its own.
+        let indices = select_amounts(&amounts, 7).unwrap();
+        assert_eq!(indices, vec![1]);
+    }
+
+    #[test]
+    fn test_select_amounts_minimizes_records() {
+        let amounts = [20, 9, 6];
+
+        // A single record (20) is preferred over two records (9 + 6), despite the larger overshoot.
+        let indices = select_amounts(&amounts, 14).unwrap();
+        assert_eq!(indices, vec![0]);
+
+        // For an equal number of records, the selection with the smaller overshoot is preferred.
+        // The largest-first selection is (10 + 9), whereas (9 + 5) is exact.
+        let amounts = [10, 9, 6, 5];
+        let indices = select_amounts(&amounts, 14).unwrap();
+        assert_eq!(sum_of(&amounts, &indices), 14);
+        assert_eq!(indices.len(), 2);
+    }
+
+    #[test]
+    fn test_select_amounts_insufficient_funds() {
+        assert!(select_amounts(&[5, 3, 1], 10).is_none());
+        assert!(select_amounts(&[], 1).is_none());
+
+        // A zero target is always covered, by the empty selection.
+        assert_eq!(select_amounts(&[], 0), Some(vec![]));
+    }
+
+    #[test]
+    fn test_select_amounts_does_not_overflow() {
+        let amounts = [u64::MAX, u64::MAX, 1];
+        let indices = select_amounts(&amounts, u64::MAX).unwrap();
+        assert_eq!(indices.len(), 1);
+        assert_eq!(amounts[indices[0]], u64::MAX);
+    }
+
+    #[test]
+    fn test_select_coins() {
+        let rng = &mut TestRng::default();
+        let records = sample_records(&[10, 7, 5, 3], rng);
+
+        // The selection (10 + 5) is returned with the commitments of the selected records.
+        let selected = select_coins(&records, 15).unwrap();
+        let expected = [0, 2].map(|index| records.get_index(index).unwrap());
+        assert_eq!(selected.iter().collect::<Vec<_>>(), expected);
+
+        // An empty record map is insufficient for any non-zero target.
+        let error = select_coins(&RecordMap::<CurrentNetwork>::new(), 1).unwrap_err();
+        assert_eq!(error.to_string(), "Insufficient funds to select records for 1 gates (balance is 0 gates)");
+
+        // A target above the balance is rejected.
+        let error = select_coins(&records, 26).unwrap_err();
+        assert_eq!(error.to_string(), "Insufficient funds to select records for 26 gates (balance is 25 gates)");
+    }
+}
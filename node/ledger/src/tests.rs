@@ -16,7 +16,10 @@
 
 use crate::{tests::test_helpers::CurrentLedger, Ledger};
 use snarkvm::{
-    console::network::{prelude::*, Testnet3},
+    console::{
+        account::ViewKey,
+        network::{prelude::*, Testnet3},
+    },
     prelude::TestRng,
     synthesizer::{block::Block, store::ConsensusStore, vm::VM, ConsensusMemory},
 };
@@ -105,3 +108,32 @@ fn test_state_path() {
 
     let _state_path = ledger.get_state_path_for_commitment(commitment).unwrap();
 }
+
+#[test]
+fn test_select_unspent_records() {
+    let rng = &mut TestRng::default();
+
+    // Sample the genesis private key.
+    let private_key = crate::tests::test_helpers::sample_genesis_private_key(rng);
+    let view_key = ViewKey::try_from(private_key).unwrap();
+    // Initialize the store.
+    let store = ConsensusStore::<_, ConsensusMemory<_>>::open(None).unwrap();
+    // Create a genesis block.
+    let genesis = Block::genesis(&VM::from(store).unwrap(), &private_key, rng).unwrap();
+    // Initialize the ledger with the genesis block.
+    let ledger = CurrentLedger::load(genesis, None).unwrap();
+
+    // Retrieve the unspent records and their balance.
+    let records = ledger.find_unspent_records(&view_key).unwrap();
+    let balance = records.values().map(|record| ***record.gates()).sum::<u64>();
+    assert!(!records.is_empty());
+
+    // Ensure selecting the full balance returns every unspent record, with its commitment.
+    assert_eq!(ledger.select_unspent_records(&view_key, balance).unwrap(), records);
+    // Ensure selecting more than the balance fails.
+    let error = ledger.select_unspent_records(&view_key, balance + 1).unwrap_err();
+    assert_eq!(
+        error.to_string(),
+        format!("Insufficient funds to select records for {} gates (balance is {balance} gates)", balance + 1)
+    );
+}
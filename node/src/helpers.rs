@@ -18,8 +18,9 @@ use snarkos_node_ledger::Ledger;
 use snarkos_node_messages::{BlockLocators, CHECKPOINT_INTERVAL, NUM_RECENTS};
 use snarkvm::prelude::{ConsensusStorage, Network};
 
-use anyhow::Result;
+use anyhow::{ensure, Result};
 use indexmap::IndexMap;
+use rayon::{ThreadPool, ThreadPoolBuilder};
 
 /// Returns the block locators for the given ledger.
 pub fn get_block_locators<N: Network, C: ConsensusStorage<N>>(ledger: &Ledger<N, C>) -> Result<BlockLocators<N>> {
@@ -53,3 +54,13 @@ pub fn log_clean_error(dev: Option<u16>) {
         None => error!("Storage corruption detected! Run `snarkos clean` to reset storage"),
     }
 }
+
+/// Returns a new thread pool with the given number of threads, named with the given prefix.
+pub fn thread_pool(name: &'static str, num_threads: usize) -> Result<ThreadPool> {
+    ensure!(num_threads > 0, "The '{name}' thread pool requires at least one thread");
+    Ok(ThreadPoolBuilder::new()
+        .stack_size(8 * 1024 * 1024)
+        .num_threads(num_threads)
+        .thread_name(move |index| format!("{name}-{index}"))
+        .build()?)
+}
@@ -94,9 +94,13 @@ impl<N: Network> Node<N> {
         account: Account<N>,
         trusted_peers: &[SocketAddr],
         genesis: Block<N>,
+        proving_threads: Option<usize>,
+        verifying_threads: Option<usize>,
         dev: Option<u16>,
     ) -> Result<Self> {
-        Ok(Self::Prover(Arc::new(Prover::new(node_ip, account, trusted_peers, genesis, dev).await?)))
+        Ok(Self::Prover(Arc::new(
+            Prover::new(node_ip, account, trusted_peers, genesis, proving_threads, verifying_threads, dev).await?,
+        )))
     }
 
     /// Initializes a new client node.
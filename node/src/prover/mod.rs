@@ -16,7 +16,7 @@
 
 mod router;
 
-use crate::traits::NodeInterface;
+use crate::{helpers::thread_pool, traits::NodeInterface};
 use snarkos_account::Account;
 use snarkos_node_messages::{Data, Message, NodeType, PuzzleResponse, UnconfirmedSolution};
 use snarkos_node_router::{Heartbeat, Inbound, Outbound, Router, Routing};
@@ -31,6 +31,7 @@ use colored::Colorize;
 use core::{marker::PhantomData, time::Duration};
 use parking_lot::RwLock;
 use rand::{rngs::OsRng, CryptoRng, Rng};
+use rayon::ThreadPool;
 use std::{
     net::SocketAddr,
     sync::{
@@ -57,6 +58,10 @@ pub struct Prover<N: Network, C: ConsensusStorage<N>> {
     puzzle_instances: Arc<AtomicU8>,
     /// The maximum number of puzzle instances.
     max_puzzle_instances: u8,
+    /// The thread pool for proving, if configured (otherwise, the global thread pool is used).
+    proving_pool: Option<Arc<ThreadPool>>,
+    /// The thread pool for verifying, if configured (otherwise, the global thread pool is used).
+    verifying_pool: Option<Arc<ThreadPool>>,
     /// The spawned handles.
     handles: Arc<RwLock<Vec<JoinHandle<()>>>>,
     /// The shutdown signal.
@@ -72,6 +77,8 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         account: Account<N>,
         trusted_peers: &[SocketAddr],
         genesis: Block<N>,
+        proving_threads: Option<usize>,
+        verifying_threads: Option<usize>,
         dev: Option<u16>,
     ) -> Result<Self> {
         // Initialize the node router.
@@ -88,6 +95,9 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         let coinbase_puzzle = CoinbasePuzzle::<N>::load()?;
         // Compute the maximum number of puzzle instances.
         let max_puzzle_instances = num_cpus::get().saturating_sub(2).clamp(1, 6);
+        // Initialize the proving and verifying thread pools, if configured.
+        let proving_pool = proving_threads.map(|num_threads| thread_pool("proving", num_threads)).transpose()?;
+        let verifying_pool = verifying_threads.map(|num_threads| thread_pool("verifying", num_threads)).transpose()?;
         // Initialize the node.
         let node = Self {
             router,
@@ -97,6 +107,8 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
             latest_block_header: Default::default(),
             puzzle_instances: Default::default(),
             max_puzzle_instances: u8::try_from(max_puzzle_instances)?,
+            proving_pool: proving_pool.map(Arc::new),
+            verifying_pool: verifying_pool.map(Arc::new),
             handles: Default::default(),
             shutdown: Default::default(),
             _phantom: Default::default(),
@@ -220,8 +232,7 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
 
         // Compute the prover solution.
         let result = self
-            .coinbase_puzzle
-            .prove(&epoch_challenge, self.address(), rng.gen(), Some(proof_target))
+            .prove_solution(&epoch_challenge, rng.gen(), proof_target)
             .ok()
             .and_then(|solution| solution.to_target().ok().map(|solution_target| (solution_target, solution)));
 
@@ -231,6 +242,44 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         result
     }
 
+    /// Computes a prover solution for the given epoch challenge and nonce, in the proving thread pool.
+    fn prove_solution(
+        &self,
+        epoch_challenge: &EpochChallenge<N>,
+        nonce: u64,
+        proof_target: u64,
+    ) -> Result<ProverSolution<N>> {
+        self.in_proving_pool(|| self.coinbase_puzzle.prove(epoch_challenge, self.address(), nonce, Some(proof_target)))
+    }
+
+    /// Verifies the given prover solution for the given epoch challenge, in the verifying thread pool.
+    fn verify_solution(
+        &self,
+        solution: &ProverSolution<N>,
+        epoch_challenge: &EpochChallenge<N>,
+        proof_target: u64,
+    ) -> Result<bool> {
+        self.in_verifying_pool(|| {
+            solution.verify(self.coinbase_puzzle.coinbase_verifying_key(), epoch_challenge, proof_target)
+        })
+    }
+
+    /// Runs the given operation in the proving thread pool, or on the current thread if it is not configured.
+    fn in_proving_pool<T: Send>(&self, op: impl FnOnce() -> T + Send) -> T {
+        match &self.proving_pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
+    /// Runs the given operation in the verifying thread pool, or on the current thread if it is not configured.
+    fn in_verifying_pool<T: Send>(&self, op: impl FnOnce() -> T + Send) -> T {
+        match &self.verifying_pool {
+            Some(pool) => pool.install(op),
+            None => op(),
+        }
+    }
+
     /// Broadcasts the prover solution to the network.
     fn broadcast_prover_solution(&self, prover_solution: ProverSolution<N>) {
         // Prepare the unconfirmed solution message.
@@ -261,3 +310,73 @@ impl<N: Network, C: ConsensusStorage<N>> Prover<N, C> {
         trace!("Number of Instances - {}", self.num_puzzle_instances());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use snarkvm::prelude::{ConsensusMemory, FromBytes, TestRng, Testnet3};
+
+    use core::str::FromStr;
+
+    type CurrentNetwork = Testnet3;
+
+    /// Returns a prover with the given number of proving and verifying threads.
+    async fn sample_prover(
+        proving_threads: Option<usize>,
+        verifying_threads: Option<usize>,
+    ) -> Prover<CurrentNetwork, ConsensusMemory<CurrentNetwork>> {
+        let account =
+            Account::<CurrentNetwork>::from_str("APrivateKey1zkp2oVPTci9kKcUprnbzMwq95Di1MQERpYBhEeqvkrDirK1").unwrap();
+        let genesis = Block::<CurrentNetwork>::from_bytes_le(CurrentNetwork::genesis_bytes()).unwrap();
+        Prover::new("127.0.0.1:0".parse().unwrap(), account, &[], genesis, proving_threads, verifying_threads, None)
+            .await
+            .unwrap()
+    }
+
+    /// Returns the name of the current thread.
+    fn thread_name() -> String {
+        std::thread::current().name().unwrap_or_default().to_string()
+    }
+
+    #[tokio::test]
+    async fn test_thread_pools() {
+        let rng = &mut TestRng::default();
+
+        // Initialize a prover with 3 proving threads and 1 verifying thread.
+        let prover = sample_prover(Some(3), Some(1)).await;
+
+        // Ensure proving and verifying run in their respective thread pools.
+        assert!(prover.in_proving_pool(thread_name).starts_with("proving-"));
+        assert!(prover.in_verifying_pool(thread_name).starts_with("verifying-"));
+        assert_eq!(prover.in_proving_pool(rayon::current_num_threads), 3);
+        assert_eq!(prover.in_verifying_pool(rayon::current_num_threads), 1);
+
+        // Ensure a solution proven in the proving thread pool is valid in the verifying thread pool.
+        let epoch_challenge =
+            EpochChallenge::new(0, Default::default(), CurrentNetwork::COINBASE_PUZZLE_DEGREE).unwrap();
+        let solution = prover.prove_solution(&epoch_challenge, rng.gen(), 0).unwrap();
+        assert!(prover.verify_solution(&solution, &epoch_challenge, 0).unwrap());
+
+        prover.shut_down().await;
+    }
+
+    #[tokio::test]
+    async fn test_thread_pools_default() {
+        // Initialize a prover without thread pools.
+        let prover = sample_prover(None, None).await;
+
+        // Ensure proving and verifying run on the current thread, outside of any thread pool.
+        let current = thread_name();
+        assert_eq!(prover.in_proving_pool(thread_name), current);
+        assert_eq!(prover.in_verifying_pool(thread_name), current);
+        assert_eq!(prover.in_proving_pool(rayon::current_thread_index), None);
+        assert_eq!(prover.in_verifying_pool(rayon::current_thread_index), None);
+
+        // Ensure their parallel work is sized for the global thread pool.
+        let num_global_threads = rayon::current_num_threads();
+        assert_eq!(prover.in_proving_pool(rayon::current_num_threads), num_global_threads);
+        assert_eq!(prover.in_verifying_pool(rayon::current_num_threads), num_global_threads);
+
+        prover.shut_down().await;
+    }
+}
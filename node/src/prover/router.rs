@@ -187,11 +187,10 @@ impl<N: Network, C: ConsensusStorage<N>> Inbound<N> for Prover<N, C> {
 
         if let (Some(epoch_challenge), Some(proof_target)) = (epoch_challenge, proof_target) {
             // Ensure that the prover solution is valid for the given epoch.
-            let coinbase_puzzle = self.coinbase_puzzle.clone();
-            let is_valid = tokio::task::spawn_blocking(move || {
-                solution.verify(coinbase_puzzle.coinbase_verifying_key(), &epoch_challenge, proof_target)
-            })
-            .await;
+            let prover = self.clone();
+            let is_valid =
+                tokio::task::spawn_blocking(move || prover.verify_solution(&solution, &epoch_challenge, proof_target))
+                    .await;
 
             match is_valid {
                 // If the solution is valid, propagate the `UnconfirmedSolution`.